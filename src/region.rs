@@ -0,0 +1,62 @@
+use crate::sweep::{adjacent, coord_from_index};
+use bit_set::BitSet;
+use std::collections::VecDeque;
+
+pub(crate) type RegionId = usize;
+
+/// A connected component of zero-adjacent ("open") tiles, plus the ring of
+/// numbered tiles bounding it. Exposing any member tile opens the whole
+/// region and its border in one step, instead of a fresh flood-fill BFS.
+#[derive(Debug, Default)]
+pub(crate) struct Region {
+    pub(crate) members: BitSet,
+    pub(crate) border: BitSet,
+}
+
+/// Partition every zero-adjacent tile into connected regions and compute each
+/// region's numbered border ring. `region_of[i]` gives the owning region for
+/// tile `i`, or `None` for mines and numbered tiles.
+pub(crate) fn compute_regions(
+    ntiles: usize,
+    dims: &[usize],
+    mine: &BitSet,
+    adjacent_mines: &[u8],
+) -> (Vec<Option<RegionId>>, Vec<Region>) {
+    let mut region_of: Vec<Option<RegionId>> = vec![None; ntiles];
+    let mut regions = Vec::new();
+
+    let is_open = |index: usize| !mine.contains(index) && adjacent_mines[index] == 0;
+
+    for start in 0..ntiles {
+        if !is_open(start) || region_of[start].is_some() {
+            continue;
+        }
+
+        let region_id = regions.len();
+        let mut region = Region::default();
+        let mut queue = VecDeque::from(vec![start]);
+
+        while let Some(index) = queue.pop_front() {
+            if region_of[index].is_some() {
+                continue;
+            }
+            region_of[index] = Some(region_id);
+            region.members.insert(index);
+
+            let coord = coord_from_index(index, dims);
+            for neighbor in adjacent(&coord, dims) {
+                if is_open(neighbor) {
+                    if region_of[neighbor].is_none() {
+                        queue.push_back(neighbor);
+                    }
+                } else if !mine.contains(neighbor) {
+                    region.border.insert(neighbor);
+                }
+            }
+        }
+
+        regions.push(region);
+    }
+
+    (region_of, regions)
+}
@@ -0,0 +1,312 @@
+use crate::sweep::{Board, Coordinate};
+use std::collections::{HashMap, HashSet};
+
+/// A single constraint derived from one exposed, numbered tile: the sum of
+/// mine-indicators over `vars` must equal `value`.
+#[derive(Debug, Clone)]
+struct Constraint {
+    vars: HashSet<Coordinate>,
+    value: usize,
+}
+
+/// The result of running constraint propagation over the currently exposed
+/// tiles: cells provably free of a mine and cells provably holding one.
+#[derive(Debug, Default)]
+pub(crate) struct Deductions {
+    pub(crate) safe: HashSet<Coordinate>,
+    pub(crate) mines: HashSet<Coordinate>,
+}
+
+impl Board {
+    /// Derive every safe and mined covered cell that follows logically from
+    /// the currently exposed numbers, without guessing.
+    pub(crate) fn deduce(&self) -> Deductions {
+        self.propagate().0
+    }
+
+    /// The covered, unflagged cell least likely to hold a mine, judged by
+    /// enumerating every satisfying mine assignment for each connected group
+    /// of constraints (components too large to enumerate are skipped rather
+    /// than scored; see `MAX_PROBABILITY_VARS`). Returns `None` once no
+    /// covered cell remains to guess.
+    pub(crate) fn best_guess(&self) -> Option<Coordinate> {
+        let (deductions, constraints) = self.propagate();
+
+        let mut best: Option<(Coordinate, f64)> = None;
+        for component in connected_components(&constraints) {
+            for (coord, probability) in probabilities(&component) {
+                if deductions.safe.contains(&coord) || deductions.mines.contains(&coord) {
+                    continue;
+                }
+                let is_better = match &best {
+                    Some((_, p)) => probability < *p,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((coord, probability));
+                }
+            }
+        }
+
+        // no scored candidate, either because nothing is ambiguous or every
+        // remaining component was too large to enumerate: fall back to any
+        // covered, unflagged cell that propagation hasn't already proven is a
+        // mine (a deduced-safe cell is preferred, if one is sitting there
+        // unclaimed)
+        best.map(|(coord, _)| coord)
+            .or_else(|| deductions.safe.iter().next().cloned())
+            .or_else(|| {
+                self.coordinates().find(|coord| {
+                    !deductions.mines.contains(coord)
+                        && self
+                            .tile(coord)
+                            .map(|tile| !tile.exposed() && !tile.flagged())
+                            .unwrap_or(false)
+                })
+            })
+    }
+
+    /// Build the initial per-tile constraints and run the base and subset
+    /// rules to fixpoint, returning the deductions made plus whatever
+    /// constraints remain ambiguous.
+    fn propagate(&self) -> (Deductions, Vec<Constraint>) {
+        let mut constraints = self.initial_constraints();
+        let mut deductions = Deductions::default();
+
+        loop {
+            let mut changed = false;
+
+            let mut remaining = Vec::new();
+            for constraint in constraints.drain(..) {
+                if constraint.value == 0 {
+                    deductions.safe.extend(constraint.vars.iter().cloned());
+                    changed = true;
+                } else if constraint.value == constraint.vars.len() {
+                    deductions.mines.extend(constraint.vars.iter().cloned());
+                    changed = true;
+                } else {
+                    remaining.push(constraint);
+                }
+            }
+            constraints = remaining;
+
+            for constraint in constraints.iter_mut() {
+                let before = constraint.vars.len();
+                let resolved_mines = constraint
+                    .vars
+                    .iter()
+                    .filter(|v| deductions.mines.contains(*v))
+                    .count();
+                constraint
+                    .vars
+                    .retain(|v| !deductions.safe.contains(v) && !deductions.mines.contains(v));
+                if constraint.vars.len() != before {
+                    constraint.value -= resolved_mines;
+                    changed = true;
+                }
+            }
+            constraints.retain(|c| !c.vars.is_empty());
+
+            let mut derived = Vec::new();
+            for a in &constraints {
+                for b in &constraints {
+                    if a.vars.len() < b.vars.len()
+                        && a.vars.is_subset(&b.vars)
+                        && b.value >= a.value
+                    {
+                        let vars: HashSet<Coordinate> =
+                            b.vars.difference(&a.vars).cloned().collect();
+                        let value = b.value - a.value;
+                        let is_new = !constraints
+                            .iter()
+                            .chain(derived.iter())
+                            .any(|c| c.vars == vars && c.value == value);
+                        if is_new {
+                            derived.push(Constraint { vars, value });
+                        }
+                    }
+                }
+            }
+            if !derived.is_empty() {
+                constraints.extend(derived);
+                changed = true;
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        (deductions, constraints)
+    }
+
+    /// One constraint per exposed, numbered tile that still has covered,
+    /// unflagged neighbors: the count of mines among those neighbors equals
+    /// the tile's `adjacent_mines` minus its already-flagged neighbors.
+    fn initial_constraints(&self) -> Vec<Constraint> {
+        self.coordinates()
+            .filter_map(|coord| {
+                let tile = self.tile(&coord).ok()?;
+                if !tile.exposed() {
+                    return None;
+                }
+
+                let mut vars = HashSet::new();
+                let mut flagged = 0usize;
+                for neighbor in self.neighbor_coords(&coord) {
+                    let ntile = self.tile(&neighbor).ok()?;
+                    if ntile.flagged() {
+                        flagged += 1;
+                    } else if !ntile.exposed() {
+                        vars.insert(neighbor);
+                    }
+                }
+
+                if vars.is_empty() {
+                    return None;
+                }
+
+                let value = usize::from(tile.adjacent_mines()).saturating_sub(flagged);
+                Some(Constraint { vars, value })
+            })
+            .collect()
+    }
+}
+
+/// Group constraints that share at least one variable; each group can be
+/// enumerated independently since its cells don't affect any other group.
+fn connected_components(constraints: &[Constraint]) -> Vec<Vec<Constraint>> {
+    let mut components: Vec<Vec<Constraint>> = Vec::new();
+
+    for constraint in constraints {
+        let mut merged = vec![constraint.clone()];
+        components.retain(|group: &Vec<Constraint>| {
+            let shares_var = group.iter().any(|c| !c.vars.is_disjoint(&constraint.vars));
+            if shares_var {
+                merged.extend(group.iter().cloned());
+                false
+            } else {
+                true
+            }
+        });
+        components.push(merged);
+    }
+
+    components
+}
+
+/// Enumerating every assignment is exponential in the component's variable
+/// count, so a component past this size is left unprobabilitied entirely
+/// (`best_guess` never scores its cells) rather than
+/// hanging, or overflowing the `1u64 << vars.len()` shift once a component
+/// reaches 64 variables.
+const MAX_PROBABILITY_VARS: usize = 20;
+
+/// Enumerate every mine assignment over a component's variables that
+/// satisfies all of its constraints, and return each variable's share of
+/// satisfying assignments that place a mine on it. Returns an empty map,
+/// without enumerating anything, once the component is too large to brute
+/// force (see `MAX_PROBABILITY_VARS`).
+fn probabilities(component: &[Constraint]) -> HashMap<Coordinate, f64> {
+    let vars: Vec<Coordinate> = component
+        .iter()
+        .flat_map(|c| c.vars.iter().cloned())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    if vars.len() > MAX_PROBABILITY_VARS {
+        return HashMap::new();
+    }
+
+    let mut mine_counts: HashMap<Coordinate, usize> =
+        vars.iter().cloned().map(|v| (v, 0)).collect();
+    let mut total = 0usize;
+
+    for assignment in 0u64..(1u64 << vars.len()) {
+        let is_mine = |index: usize| assignment & (1 << index) != 0;
+
+        let satisfies = component.iter().all(|constraint| {
+            let count = vars
+                .iter()
+                .enumerate()
+                .filter(|&(i, v)| constraint.vars.contains(v) && is_mine(i))
+                .count();
+            count == constraint.value
+        });
+
+        if satisfies {
+            total += 1;
+            for (i, v) in vars.iter().enumerate() {
+                if is_mine(i) {
+                    *mine_counts.get_mut(v).unwrap() += 1;
+                }
+            }
+        }
+    }
+
+    if total == 0 {
+        return HashMap::new();
+    }
+
+    mine_counts
+        .into_iter()
+        .map(|(v, count)| (v, count as f64 / total as f64))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deduce_marks_zero_constraint_as_safe() {
+        let mut board = Board::new_2d(3, 3, 0).unwrap();
+        // expose only the corner, without the flood fill `expose` would run,
+        // so its three covered neighbors form one constraint with value 0
+        board.tile_mut(&[0, 0]).unwrap().set_exposed(true);
+
+        let deductions = board.deduce();
+
+        for neighbor in board.neighbor_coords(&[0, 0]) {
+            assert!(deductions.safe.contains(&neighbor));
+        }
+        for coord in board.coordinates() {
+            assert!(!deductions.mines.contains(&coord));
+        }
+    }
+
+    #[test]
+    fn test_deductions_never_contradict_ground_truth() {
+        let mut board = Board::with_safe_start(vec![8, 8], 10, true).unwrap();
+        board.expose(vec![4, 4]).unwrap();
+
+        let deductions = board.deduce();
+
+        for coord in &deductions.safe {
+            assert!(!board.tile(coord).unwrap().mine());
+        }
+        for coord in &deductions.mines {
+            assert!(board.tile(coord).unwrap().mine());
+        }
+    }
+
+    #[test]
+    fn test_probabilities_skips_oversized_components_instead_of_hanging() {
+        let vars: HashSet<Coordinate> = (0..(MAX_PROBABILITY_VARS + 5)).map(|i| vec![0, i]).collect();
+        let component = vec![Constraint { vars, value: 1 }];
+
+        assert!(probabilities(&component).is_empty());
+    }
+
+    #[test]
+    fn test_best_guess_avoids_deduced_mines() {
+        let mut board = Board::with_safe_start(vec![8, 8], 10, true).unwrap();
+        board.expose(vec![4, 4]).unwrap();
+
+        let deductions = board.deduce();
+        if let Some(guess) = board.best_guess() {
+            assert!(!deductions.mines.contains(&guess));
+        }
+    }
+}
@@ -1,66 +1,133 @@
 use crate::error::Error;
+use crate::region::{compute_regions, Region, RegionId};
 use bit_set::BitSet;
-use std::collections::VecDeque;
 
-pub(crate) type Coordinate = (usize, usize);
+/// A point on the board: one index per axis. A 2-D board uses `vec![row, column]`.
+pub(crate) type Coordinate = Vec<usize>;
+
+/// Every non-zero offset vector in `{-1, 0, 1}^d`: the Cartesian product of
+/// the three per-axis increments across all `d` dimensions, minus the
+/// all-zero offset (which would just be the tile itself).
+fn offsets(d: usize) -> impl Iterator<Item = Vec<i64>> {
+    let total = 3usize.pow(d as u32);
+    (0..total).filter_map(move |mut code| {
+        let mut offset = Vec::with_capacity(d);
+        for _ in 0..d {
+            offset.push((code % 3) as i64 - 1);
+            code /= 3;
+        }
+        if offset.iter().all(|&o| o == 0) {
+            None
+        } else {
+            Some(offset)
+        }
+    })
+}
+
+pub(crate) fn adjacent<'a>(coord: &[usize], dims: &'a [usize]) -> impl Iterator<Item = usize> + 'a {
+    let coord = coord.to_vec();
+    offsets(dims.len()).filter_map(move |offset| {
+        let mut neighbor = Vec::with_capacity(coord.len());
+        for (axis, &o) in offset.iter().enumerate() {
+            let value = coord[axis] as i64 + o;
+            if value < 0 || value as usize >= dims[axis] {
+                return None;
+            }
+            neighbor.push(value as usize);
+        }
+        Some(index_from_coord(&neighbor, dims))
+    })
+}
+
+/// How many tiles an expanding board grows by, at one end of an axis, each
+/// time the flood fill reaches that end of the axis.
+const GROWTH_STEP: usize = 4;
 
-#[derive(Debug)]
-pub(crate) struct Tile {
-    adjacent_tiles: BitSet,
-    pub(crate) mine: bool,
-    pub(crate) exposed: bool,
-    pub(crate) flagged: bool,
-    pub(crate) adjacent_mines: u8,
+fn set_bit(set: &mut BitSet, index: usize, value: bool) {
+    if value {
+        set.insert(index);
+    } else {
+        set.remove(index);
+    }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-enum Increment {
-    One,
-    NegOne,
-    Zero,
+/// A read-only view onto one tile, backed by the board's bitsets rather than
+/// a per-tile struct, so callers keep the familiar `tile().mine()` ergonomics.
+pub(crate) struct TileView<'a> {
+    board: &'a Board,
+    index: usize,
 }
 
-impl Increment {
-    fn offset(&self, value: usize) -> usize {
-        match *self {
-            Self::One => value + 1,
-            Self::NegOne => value.saturating_sub(1),
-            Self::Zero => value,
-        }
+impl TileView<'_> {
+    pub(crate) fn mine(&self) -> bool {
+        self.board.mine.contains(self.index)
+    }
+
+    pub(crate) fn exposed(&self) -> bool {
+        self.board.exposed.contains(self.index)
+    }
+
+    pub(crate) fn flagged(&self) -> bool {
+        self.board.flagged.contains(self.index)
+    }
+
+    pub(crate) fn adjacent_mines(&self) -> u8 {
+        self.board.adjacent_mines[self.index]
     }
 }
 
-fn adjacent((row, column): Coordinate, rows: usize, columns: usize) -> impl Iterator<Item = usize> {
-    const INCREMENTS: [Increment; 3] = [Increment::One, Increment::NegOne, Increment::Zero];
+/// A writable view onto one tile, backed by the board's bitsets.
+pub(crate) struct TileViewMut<'a> {
+    board: &'a mut Board,
+    index: usize,
+}
 
-    INCREMENTS
-        .iter()
-        .copied()
-        .flat_map(|row_incr| std::iter::repeat(row_incr).zip(INCREMENTS))
-        .filter_map(move |(row_incr, column_incr)| {
-            let row_offset = row_incr.offset(row);
-            let column_offset = column_incr.offset(column);
+impl TileViewMut<'_> {
+    pub(crate) fn mine(&self) -> bool {
+        self.board.mine.contains(self.index)
+    }
 
-            if row_offset == row && column_offset == column {
-                return None;
-            }
+    pub(crate) fn set_mine(&mut self, value: bool) {
+        set_bit(&mut self.board.mine, self.index, value);
+    }
 
-            match (row_incr, column_incr) {
-                (Increment::Zero, Increment::Zero) => None,
-                (_, _) if row_offset < rows && column_offset < columns => {
-                    Some(index_from_coord((row_offset, column_offset), columns))
-                }
-                _ => None,
-            }
-        })
+    pub(crate) fn exposed(&self) -> bool {
+        self.board.exposed.contains(self.index)
+    }
+
+    pub(crate) fn set_exposed(&mut self, value: bool) {
+        set_bit(&mut self.board.exposed, self.index, value);
+    }
+
+    pub(crate) fn flagged(&self) -> bool {
+        self.board.flagged.contains(self.index)
+    }
+
+    pub(crate) fn set_flagged(&mut self, value: bool) {
+        set_bit(&mut self.board.flagged, self.index, value);
+    }
+
+    pub(crate) fn adjacent_mines(&self) -> u8 {
+        self.board.adjacent_mines[self.index]
+    }
 }
 
 pub(crate) struct Board {
-    tiles: Vec<Tile>,
-    // number of rows on the board
-    pub(crate) rows: usize,
-    // number of columns on the board
-    pub(crate) columns: usize,
+    // which tiles are mined, exposed, and flagged, one bit per tile
+    mine: BitSet,
+    exposed: BitSet,
+    flagged: BitSet,
+    // one byte per tile; neighbor indices are recomputed on demand via
+    // `adjacent` rather than cached per tile
+    adjacent_mines: Vec<u8>,
+    // the connected component of zero-adjacent tiles each tile belongs to,
+    // or `None` for mines and numbered tiles; recomputed whenever mines are
+    // (re)seeded
+    region_of: Vec<Option<RegionId>>,
+    regions: Vec<Region>,
+    // the size of each axis, e.g. `[rows, columns]` for a 2-D board
+    pub(crate) dims: Vec<usize>,
+    ntiles: usize,
     // the total number of mines
     mines: usize,
     flagged_cells: usize,
@@ -68,55 +135,286 @@ pub(crate) struct Board {
     correctly_flagged_mines: usize,
     // the exposed tiles
     seen: BitSet<usize>,
+    // true once mines have been sampled onto the board; false means placement
+    // is deferred until the first `expose` call (see `safe_start`)
+    seeded: bool,
+    // true for an auto-growing board: `expose` grows whichever axis the flood
+    // fill reaches the edge of, instead of treating `dims` as fixed
+    expanding: bool,
+    // the fraction of freshly added cells seeded as mines when an expanding
+    // board grows; unused unless `expanding` is set
+    mine_density: f64,
 }
 
-fn index_from_coord((r, c): Coordinate, columns: usize) -> usize {
-    r * columns + c
+fn strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; dims.len()];
+    for k in (0..dims.len().saturating_sub(1)).rev() {
+        strides[k] = strides[k + 1] * dims[k + 1];
+    }
+    strides
+}
+
+fn index_from_coord(coord: &[usize], dims: &[usize]) -> usize {
+    coord.iter().zip(strides(dims)).map(|(c, s)| c * s).sum()
 }
 
-fn coord_from_index(index: usize, columns: usize) -> Coordinate {
-    (index / columns, index % columns)
+pub(crate) fn coord_from_index(index: usize, dims: &[usize]) -> Coordinate {
+    let mut index = index;
+    strides(dims)
+        .into_iter()
+        .map(|s| {
+            let c = index / s;
+            index %= s;
+            c
+        })
+        .collect()
 }
 
 impl Board {
-    pub(crate) fn new(rows: usize, columns: usize, mines: usize) -> Result<Self, Error> {
-        let mut rng = rand::thread_rng();
-        let samples = rand::seq::index::sample(&mut rng, rows * columns, mines)
-            .into_iter()
-            .collect::<BitSet>();
-
-        let tiles = (0..rows)
-            .flat_map(|row| std::iter::repeat(row).zip(0..columns))
-            .enumerate()
-            .map(|(i, point)| {
-                // compute the tiles adjacent to the one being constructed
-                let adjacent_tiles = adjacent(point, rows, columns).collect::<BitSet>();
-
-                // sum the number of adjacent tiles that are in the randomly generated mines set
-                let adjacent_mines = adjacent_tiles
-                    .iter()
-                    .fold(0, |total, index| total + u8::from(samples.contains(index)));
-                assert!(adjacent_mines <= 8);
-
-                Tile {
-                    adjacent_tiles,
-                    mine: samples.contains(i),
-                    exposed: false,
-                    flagged: false,
-                    adjacent_mines,
-                }
-            })
-            .collect::<Vec<_>>();
+    pub(crate) fn new(dims: Vec<usize>, mines: usize) -> Result<Self, Error> {
+        Self::with_safe_start(dims, mines, false)
+    }
 
-        Ok(Self {
-            rows,
-            columns,
-            tiles,
+    /// Convenience constructor for the common 2-D case.
+    pub(crate) fn new_2d(rows: usize, columns: usize, mines: usize) -> Result<Self, Error> {
+        Self::new(vec![rows, columns], mines)
+    }
+
+    /// Build a board. When `safe_start` is set, mine placement is deferred:
+    /// the board starts out entirely mine-free and `seed_excluding` is run
+    /// lazily on the first `expose`, so the opening click can never detonate
+    /// a mine. It also lands on a zero-adjacent tile that floods, unless the
+    /// board is too dense to keep the clicked cell's neighbors mine-free too.
+    pub(crate) fn with_safe_start(
+        dims: Vec<usize>,
+        mines: usize,
+        safe_start: bool,
+    ) -> Result<Self, Error> {
+        let ntiles = dims.iter().product::<usize>();
+
+        let mut board = Self {
+            mine: BitSet::with_capacity(ntiles),
+            exposed: BitSet::with_capacity(ntiles),
+            flagged: BitSet::with_capacity(ntiles),
+            adjacent_mines: vec![0; ntiles],
+            region_of: vec![None; ntiles],
+            regions: Vec::new(),
+            dims,
+            ntiles,
             mines,
             flagged_cells: Default::default(),
             correctly_flagged_mines: Default::default(),
             seen: Default::default(),
-        })
+            seeded: false,
+            expanding: false,
+            mine_density: 0.0,
+        };
+
+        if !safe_start {
+            board.seed_excluding(None);
+        }
+
+        Ok(board)
+    }
+
+    /// Build an auto-growing board: it starts at `dims` and, whenever `expose`
+    /// floods to either edge of an axis, grows that axis by `GROWTH_STEP`
+    /// tiles at that edge, sampling mines in the freshly added cells at
+    /// `mine_density`.
+    /// Always starts with a safe first click, since a deferred board never
+    /// needs to gamble on the opening move.
+    pub(crate) fn new_expanding(dims: Vec<usize>, mine_density: f64) -> Result<Self, Error> {
+        let ntiles = dims.iter().product::<usize>();
+        let mines = (ntiles as f64 * mine_density).round() as usize;
+
+        let mut board = Self::with_safe_start(dims, mines, true)?;
+        board.expanding = true;
+        board.mine_density = mine_density;
+        Ok(board)
+    }
+
+    /// Sample `mines` mines onto the board, then recompute every tile's
+    /// `adjacent_mines`. If `exclude` is given, the clicked cell and its
+    /// neighbors are kept mine-free so the first expose is guaranteed safe;
+    /// if the board is too dense to honor the full neighborhood, only the
+    /// clicked cell itself is excluded.
+    fn seed_excluding(&mut self, exclude: Option<Coordinate>) {
+        let ntiles = self.ntiles;
+
+        let excluded: BitSet = match exclude {
+            Some(coord) => {
+                let neighborhood = std::iter::once(self.index_from_coord(&coord))
+                    .chain(adjacent(&coord, &self.dims))
+                    .collect::<BitSet>();
+
+                if ntiles - neighborhood.len() >= self.mines {
+                    neighborhood
+                } else {
+                    std::iter::once(self.index_from_coord(&coord)).collect()
+                }
+            }
+            None => BitSet::new(),
+        };
+
+        let candidates = (0..ntiles)
+            .filter(|index| !excluded.contains(*index))
+            .collect::<Vec<_>>();
+
+        // `exclude` always keeps at least the clicked cell mine-free, so
+        // `candidates` can be smaller than `self.mines` once the board is
+        // dense enough (e.g. a 100%-density expanding board); clamp rather
+        // than let `sample` panic on an out-of-range amount
+        self.mines = self.mines.min(candidates.len());
+
+        let mut rng = rand::thread_rng();
+        self.mine = rand::seq::index::sample(&mut rng, candidates.len(), self.mines)
+            .into_iter()
+            .map(|i| candidates[i])
+            .collect();
+
+        for index in 0..ntiles {
+            let coord = coord_from_index(index, &self.dims);
+            self.adjacent_mines[index] = adjacent(&coord, &self.dims)
+                .filter(|neighbor| self.mine.contains(*neighbor))
+                .count() as u8;
+        }
+
+        self.recompute_regions();
+
+        self.seeded = true;
+    }
+
+    fn recompute_regions(&mut self) {
+        let (region_of, regions) =
+            compute_regions(self.ntiles, &self.dims, &self.mine, &self.adjacent_mines);
+        self.region_of = region_of;
+        self.regions = regions;
+    }
+
+    /// Grow `axis` by `amount` tiles at one end: reallocate every bitset
+    /// and `adjacent_mines` into the enlarged `dims`, copying existing tiles
+    /// to their new linear indices, then sample mines into the newly added
+    /// cells at `mine_density` and recompute `adjacent_mines` for the new
+    /// border. Regions are not recomputed here.
+    /// `prepend` selects which end of `axis` grows: `false` appends `amount`
+    /// tiles past the current far edge, `true` inserts them before the
+    /// current near (index-0) edge, shifting every existing coordinate's
+    /// `axis` component up by `amount`.
+    fn grow(&mut self, axis: usize, amount: usize, prepend: bool) {
+        let old_dims = self.dims.clone();
+        let old_ntiles = self.ntiles;
+
+        let mut new_dims = old_dims.clone();
+        new_dims[axis] += amount;
+        let new_ntiles = new_dims.iter().product::<usize>();
+
+        let mut mine = BitSet::with_capacity(new_ntiles);
+        let mut exposed = BitSet::with_capacity(new_ntiles);
+        let mut flagged = BitSet::with_capacity(new_ntiles);
+        let mut seen: BitSet<usize> = Default::default();
+        let mut adjacent_mines = vec![0u8; new_ntiles];
+
+        for old_index in 0..old_ntiles {
+            let mut coord = coord_from_index(old_index, &old_dims);
+            if prepend {
+                coord[axis] += amount;
+            }
+            let new_index = index_from_coord(&coord, &new_dims);
+            set_bit(&mut mine, new_index, self.mine.contains(old_index));
+            set_bit(&mut exposed, new_index, self.exposed.contains(old_index));
+            set_bit(&mut flagged, new_index, self.flagged.contains(old_index));
+            if self.seen.contains(old_index) {
+                seen.insert(new_index);
+            }
+            adjacent_mines[new_index] = self.adjacent_mines[old_index];
+        }
+
+        self.dims = new_dims;
+        self.ntiles = new_ntiles;
+        self.mine = mine;
+        self.exposed = exposed;
+        self.flagged = flagged;
+        self.seen = seen;
+        self.adjacent_mines = adjacent_mines;
+
+        let new_cells: Vec<usize> = (0..self.ntiles)
+            .filter(|&index| {
+                let c = coord_from_index(index, &self.dims)[axis];
+                if prepend {
+                    c < amount
+                } else {
+                    c >= old_dims[axis]
+                }
+            })
+            .collect();
+
+        let new_mines = (new_cells.len() as f64 * self.mine_density).round() as usize;
+        let mut rng = rand::thread_rng();
+        for index in rand::seq::index::sample(&mut rng, new_cells.len(), new_mines.min(new_cells.len())) {
+            let coord = coord_from_index(new_cells[index], &self.dims);
+            self.tile_mut(&coord).unwrap().set_mine(true);
+        }
+        self.mines += new_mines.min(new_cells.len());
+
+        // only tiles on the new side of the axis, and the old tiles that used
+        // to be the edge (and so previously had no neighbors past it), have a
+        // different neighbor set now
+        let border: Vec<usize> = (0..self.ntiles)
+            .filter(|&index| {
+                let c = coord_from_index(index, &self.dims)[axis];
+                if prepend {
+                    c <= amount
+                } else {
+                    c + 1 >= old_dims[axis]
+                }
+            })
+            .collect();
+        for index in border {
+            let coord = coord_from_index(index, &self.dims);
+            self.adjacent_mines[index] = adjacent(&coord, &self.dims)
+                .filter(|neighbor| self.mine.contains(*neighbor))
+                .count() as u8;
+        }
+
+        // regions are rebuilt once by the caller, after every axis that needs
+        // to grow this round has grown, rather than once per `grow` call
+    }
+
+    /// If `region_id` borders either edge of any axis, grow that side of the
+    /// axis so the flood fill has somewhere to continue. Returns the amount
+    /// each axis's coordinates shifted by (nonzero only where growth
+    /// prepended), so callers holding a pre-growth coordinate can relocate it.
+    fn grow_to_cover(&mut self, region_id: RegionId) -> Coordinate {
+        let dims = self.dims.clone();
+        let indices: Vec<usize> = self.regions[region_id]
+            .members
+            .iter()
+            .chain(self.regions[region_id].border.iter())
+            .collect();
+
+        let mut growth: Vec<(usize, bool)> = Vec::new();
+        for axis in 0..dims.len() {
+            let touches = |at: usize| indices.iter().any(|&index| coord_from_index(index, &dims)[axis] == at);
+            if touches(0) {
+                growth.push((axis, true));
+            }
+            if touches(dims[axis] - 1) {
+                growth.push((axis, false));
+            }
+        }
+
+        let mut shift = vec![0usize; dims.len()];
+        let grew = !growth.is_empty();
+        for (axis, prepend) in growth {
+            if prepend {
+                shift[axis] += GROWTH_STEP;
+            }
+            self.grow(axis, GROWTH_STEP, prepend);
+        }
+        if grew {
+            self.recompute_regions();
+        }
+        shift
     }
 
     pub(crate) fn available_flags(&self) -> usize {
@@ -126,86 +424,166 @@ impl Board {
 
     pub(crate) fn won(&self) -> bool {
         let nseen = self.seen.len();
+
+        if self.expanding {
+            // `ntiles` keeps growing, so there's no fixed tile count to
+            // compare against; instead check whether every tile that isn't
+            // (density-implied to be) a mine has been seen
+            return nseen >= self.ntiles - self.mines;
+        }
+
         let exposed_or_correctly_flagged = nseen + self.correctly_flagged_mines;
-        let ntiles = self.rows * self.columns;
+        assert!(exposed_or_correctly_flagged <= self.ntiles);
 
-        assert!(exposed_or_correctly_flagged <= ntiles);
+        self.ntiles == exposed_or_correctly_flagged || (self.ntiles - nseen) == self.mines
+    }
 
-        ntiles == exposed_or_correctly_flagged || (self.tiles.len() - nseen) == self.mines
+    /// The number of rows, for 2-D boards.
+    pub(crate) fn rows(&self) -> usize {
+        self.dims[0]
     }
 
-    fn index_from_coord(&self, (r, c): Coordinate) -> usize {
-        index_from_coord((r, c), self.columns)
+    /// The number of columns, for 2-D boards.
+    pub(crate) fn columns(&self) -> usize {
+        self.dims[1]
     }
 
-    pub(crate) fn expose(&mut self, (r, c): Coordinate) -> Result<bool, Error> {
-        if self.tile(r, c)?.mine {
-            self.tile_mut(r, c)?.exposed = true;
-            return Ok(true);
-        }
+    fn index_from_coord(&self, coord: &[usize]) -> usize {
+        index_from_coord(coord, &self.dims)
+    }
 
-        let mut coordinates = [(r, c)].iter().copied().collect::<VecDeque<_>>();
+    fn checked_index(&self, coord: &[usize]) -> Result<usize, Error> {
+        let index = self.index_from_coord(coord);
+        if index < self.ntiles {
+            Ok(index)
+        } else {
+            Err(Error::GetTile(coord.to_vec()))
+        }
+    }
 
-        let columns = self.columns;
+    pub(crate) fn expose(&mut self, mut coord: Coordinate) -> Result<bool, Error> {
+        if !self.seeded {
+            self.seed_excluding(Some(coord.clone()));
+        }
 
-        while let Some((r, c)) = coordinates.pop_front() {
-            if self.seen.insert(self.index_from_coord((r, c))) {
-                let tile = self.tile_mut(r, c)?;
+        let mut index = self.checked_index(&coord)?;
 
-                tile.exposed = !(tile.mine || tile.flagged);
+        if self.mine.contains(index) {
+            self.exposed.insert(index);
+            return Ok(true);
+        }
 
-                if tile.adjacent_mines == 0 {
-                    coordinates.extend(
-                        tile.adjacent_tiles
-                            .iter()
-                            .map(move |index| coord_from_index(index, columns)),
-                    );
+        if self.expanding && !self.seen.contains(index) {
+            if let Some(region_id) = self.region_of[index] {
+                let shift = self.grow_to_cover(region_id);
+                // growing may have changed every tile's linear index (an axis
+                // other than the last shifts every later stride), and growth
+                // that prepended also relocates the clicked coordinate itself
+                for (c, s) in coord.iter_mut().zip(shift) {
+                    *c += s;
                 }
-            };
+                index = self.checked_index(&coord)?;
+            }
+        }
+
+        match self.region_of[index] {
+            // a precomputed flood region: exposing any member opens the whole
+            // region and its numbered border ring in one union, no BFS needed
+            Some(region_id) => self.expose_region(region_id),
+            None => self.expose_one(index),
         }
 
         Ok(false)
     }
 
+    fn expose_one(&mut self, index: usize) {
+        if self.seen.insert(index) {
+            let newly_exposed = !(self.mine.contains(index) || self.flagged.contains(index));
+            set_bit(&mut self.exposed, index, newly_exposed);
+        }
+    }
+
+    fn expose_region(&mut self, region_id: RegionId) {
+        let indices = self.regions[region_id]
+            .members
+            .iter()
+            .chain(self.regions[region_id].border.iter())
+            .collect::<Vec<_>>();
+
+        for index in indices {
+            self.expose_one(index);
+        }
+    }
+
+    /// The numbered tiles bounding the flood opening that `coord` is part of,
+    /// or `None` if `coord` isn't a zero-adjacent tile.
+    pub(crate) fn region_border(&self, coord: &[usize]) -> Result<Option<&BitSet>, Error> {
+        let index = self.checked_index(coord)?;
+        Ok(self.region_of[index].map(|id| &self.regions[id].border))
+    }
+
     pub(crate) fn expose_all(&mut self) -> Result<(), Error> {
-        let columns = self.columns;
-        (0..self.tiles.len())
-            .map(move |i| coord_from_index(i, columns))
+        // coordinates are collected up front, so growth mid-loop (which
+        // shifts every existing tile's coordinates on a prepended axis) would
+        // leave the rest of this pass exposing the wrong tiles; suppress it
+        // for the duration, since revealing the board as it stands is the
+        // point here, not extending it
+        let was_expanding = self.expanding;
+        self.expanding = false;
+
+        let result = self
+            .coordinates()
+            .collect::<Vec<_>>()
+            .into_iter()
             .try_for_each(|coord| {
                 self.expose(coord)?;
                 Ok(())
-            })
+            });
+
+        self.expanding = was_expanding;
+        result
     }
 
-    pub(crate) fn tile(&self, i: usize, j: usize) -> Result<&Tile, Error> {
-        self.tiles
-            .get(self.index_from_coord((i, j)))
-            .ok_or(Error::GetTile((i, j)))
+    /// Every coordinate on the board, in row-major order.
+    pub(crate) fn coordinates(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        (0..self.ntiles).map(move |i| coord_from_index(i, &self.dims))
     }
 
-    pub(crate) fn tile_mut(&mut self, i: usize, j: usize) -> Result<&mut Tile, Error> {
-        let index = self.index_from_coord((i, j));
-        self.tiles.get_mut(index).ok_or(Error::GetTile((i, j)))
+    /// The coordinates adjacent to `coord`, for callers outside this module.
+    pub(crate) fn neighbor_coords(&self, coord: &[usize]) -> impl Iterator<Item = Coordinate> + '_ {
+        adjacent(coord, &self.dims).map(move |index| coord_from_index(index, &self.dims))
     }
 
+    pub(crate) fn tile(&self, coord: &[usize]) -> Result<TileView<'_>, Error> {
+        let index = self.checked_index(coord)?;
+        Ok(TileView { board: self, index })
+    }
+
+    pub(crate) fn tile_mut(&mut self, coord: &[usize]) -> Result<TileViewMut<'_>, Error> {
+        let index = self.checked_index(coord)?;
+        Ok(TileViewMut { board: self, index })
+    }
+
+    /// Flag every unexposed mine and unflag everything else, in one pass over
+    /// whole bitset words rather than tile-by-tile.
     pub(crate) fn flag_all(&mut self) {
-        for tile in self.tiles.iter_mut() {
-            tile.flagged = !tile.exposed && tile.mine;
-        }
+        self.flagged = self.mine.clone();
+        self.flagged.difference_with(&self.exposed);
     }
 
-    pub(crate) fn flag(&mut self, i: usize, j: usize) -> Result<bool, Error> {
+    pub(crate) fn flag(&mut self, coord: &[usize]) -> Result<bool, Error> {
+        let index = self.checked_index(coord)?;
         let nflagged = self.flagged_cells;
-        let tile = self.tile(i, j)?;
-        let was_flagged = tile.flagged;
+        let was_flagged = self.flagged.contains(index);
+        let is_mine = self.mine.contains(index);
         let flagged = !was_flagged;
         let nmines = self.mines;
-        self.correctly_flagged_mines += usize::from(flagged && tile.mine);
+        self.correctly_flagged_mines += usize::from(flagged && is_mine);
         if was_flagged {
             self.flagged_cells = self.flagged_cells.saturating_sub(1);
-            self.tile_mut(i, j)?.flagged = flagged;
-        } else if nflagged < nmines && !self.tile(i, j)?.exposed {
-            self.tile_mut(i, j)?.flagged = flagged;
+            set_bit(&mut self.flagged, index, flagged);
+        } else if nflagged < nmines && !self.exposed.contains(index) {
+            set_bit(&mut self.flagged, index, flagged);
             self.flagged_cells += 1;
         }
         Ok(flagged)
@@ -221,12 +599,12 @@ mod tests {
         let rows = 10;
         let columns = 10;
         let mines = 10;
-        let board = Board::new(rows, columns, mines).unwrap();
+        let board = Board::new_2d(rows, columns, mines).unwrap();
 
-        assert_eq!(board.rows, rows);
-        assert_eq!(board.columns, columns);
+        assert_eq!(board.rows(), rows);
+        assert_eq!(board.columns(), columns);
         assert_eq!(board.mines, mines);
-        assert_eq!(board.tiles.len(), rows * columns);
+        assert_eq!(board.ntiles, rows * columns);
     }
 
     #[test]
@@ -234,10 +612,9 @@ mod tests {
         let rows = 10;
         let columns = 10;
         let mines = 10;
-        let board = Board::new(rows, columns, mines).unwrap();
+        let board = Board::new_2d(rows, columns, mines).unwrap();
 
-        let mine_count = board.tiles.iter().filter(|t| t.mine).count();
-        assert_eq!(mine_count, mines);
+        assert_eq!(board.mine.len(), mines);
     }
 
     #[test]
@@ -249,11 +626,11 @@ mod tests {
         let rows = 5;
         let columns = 5;
         let mines = 5;
-        let board = Board::new(rows, columns, mines).unwrap();
+        let board = Board::new_2d(rows, columns, mines).unwrap();
 
         for r in 0..rows {
             for c in 0..columns {
-                let tile = board.tile(r, c).unwrap();
+                let tile = board.tile(&[r, c]).unwrap();
                 // Manually calculate adjacent mines
                 let mut count = 0;
                 for dr in -1..=1 {
@@ -262,13 +639,13 @@ mod tests {
                         let nr = r as isize + dr;
                         let nc = c as isize + dc;
                         if nr >= 0 && nr < rows as isize && nc >= 0 && nc < columns as isize {
-                            if board.tile(nr as usize, nc as usize).unwrap().mine {
+                            if board.tile(&[nr as usize, nc as usize]).unwrap().mine() {
                                 count += 1;
                             }
                         }
                     }
                 }
-                assert_eq!(tile.adjacent_mines, count, "Mismatch at ({}, {})", r, c);
+                assert_eq!(tile.adjacent_mines(), count, "Mismatch at ({}, {})", r, c);
             }
         }
     }
@@ -278,14 +655,13 @@ mod tests {
         let rows = 5;
         let columns = 5;
         let mines = 0; // 0 mines means safe expose everywhere
-        let mut board = Board::new(rows, columns, mines).unwrap();
+        let mut board = Board::new_2d(rows, columns, mines).unwrap();
 
         // Expose top left
-        board.expose((0, 0)).unwrap();
+        board.expose(vec![0, 0]).unwrap();
 
         // Since 0 mines, exposing one should expose all (flood fill)
-        let exposed_count = board.tiles.iter().filter(|t| t.exposed).count();
-        assert_eq!(exposed_count, rows * columns);
+        assert_eq!(board.exposed.len(), rows * columns);
     }
 
     #[test]
@@ -293,14 +669,14 @@ mod tests {
         let rows = 3;
         let columns = 3;
         let mines = 1;
-        let mut board = Board::new(rows, columns, mines).unwrap();
+        let mut board = Board::new_2d(rows, columns, mines).unwrap();
 
         // Find the mine
-        let mut mine_coord = (0, 0);
+        let mut mine_coord = vec![0, 0];
         for r in 0..rows {
             for c in 0..columns {
-                if board.tile(r, c).unwrap().mine {
-                    mine_coord = (r, c);
+                if board.tile(&[r, c]).unwrap().mine() {
+                    mine_coord = vec![r, c];
                     break;
                 }
             }
@@ -309,8 +685,8 @@ mod tests {
         // Expose all non-mine cells
         for r in 0..rows {
             for c in 0..columns {
-                if (r, c) != mine_coord {
-                    board.expose((r, c)).unwrap();
+                if vec![r, c] != mine_coord {
+                    board.expose(vec![r, c]).unwrap();
                 }
             }
         }
@@ -323,17 +699,234 @@ mod tests {
         let rows = 5;
         let columns = 5;
         let mines = 5;
-        let mut board = Board::new(rows, columns, mines).unwrap();
+        let mut board = Board::new_2d(rows, columns, mines).unwrap();
 
         // Flag a cell
         let flags_before = board.flagged_cells;
-        board.flag(0, 0).unwrap();
+        board.flag(&[0, 0]).unwrap();
         assert_eq!(board.flagged_cells, flags_before + 1);
-        assert!(board.tile(0, 0).unwrap().flagged);
+        assert!(board.tile(&[0, 0]).unwrap().flagged());
 
         // Unflag
-        board.flag(0, 0).unwrap();
+        board.flag(&[0, 0]).unwrap();
         assert_eq!(board.flagged_cells, flags_before);
-        assert!(!board.tile(0, 0).unwrap().flagged);
+        assert!(!board.tile(&[0, 0]).unwrap().flagged());
+    }
+
+    #[test]
+    fn test_safe_start_unseeded_until_first_expose() {
+        let rows = 5;
+        let columns = 5;
+        let mines = 10;
+        let board = Board::with_safe_start(vec![rows, columns], mines, true).unwrap();
+
+        assert!(!board.seeded);
+        assert_eq!(board.mine.len(), 0);
+    }
+
+    #[test]
+    fn test_safe_start_first_click_never_a_mine_and_floods() {
+        let rows = 5;
+        let columns = 5;
+        let mines = 10;
+        let mut board = Board::with_safe_start(vec![rows, columns], mines, true).unwrap();
+
+        let hit_mine = board.expose(vec![2, 2]).unwrap();
+
+        assert!(board.seeded);
+        assert!(!hit_mine);
+        assert!(!board.tile(&[2, 2]).unwrap().mine());
+        assert_eq!(board.tile(&[2, 2]).unwrap().adjacent_mines(), 0);
+        assert_eq!(board.mine.len(), mines);
+
+        // the clicked cell had no adjacent mines, so it and its neighbors flood open
+        for index in adjacent(&[2, 2], &board.dims).chain(std::iter::once(2 * columns + 2)) {
+            let coord = coord_from_index(index, &board.dims);
+            assert!(board.tile(&coord).unwrap().exposed());
+        }
+    }
+
+    #[test]
+    fn test_safe_start_falls_back_when_board_too_dense() {
+        let rows = 3;
+        let columns = 3;
+        let mines = 8;
+        let mut board = Board::with_safe_start(vec![rows, columns], mines, true).unwrap();
+
+        board.expose(vec![1, 1]).unwrap();
+
+        assert!(board.seeded);
+        assert!(!board.tile(&[1, 1]).unwrap().mine());
+        assert_eq!(board.mine.len(), mines);
+    }
+
+    #[test]
+    fn test_safe_start_clamps_mines_when_board_is_too_dense_to_place_them_all() {
+        // 4 mines on a 4-tile board leaves no room once the clicked cell is
+        // excluded, even via the single-cell fallback; sampling must clamp
+        // instead of panicking on an out-of-range amount
+        let mut board = Board::with_safe_start(vec![2, 2], 4, true).unwrap();
+
+        board.expose(vec![0, 0]).unwrap();
+
+        assert!(board.seeded);
+        assert!(!board.tile(&[0, 0]).unwrap().mine());
+        assert_eq!(board.mine.len(), 3);
+        assert_eq!(board.mines, 3);
+    }
+
+    #[test]
+    fn test_three_dimensional_adjacency() {
+        let dims = vec![3, 3, 3];
+        let mines = 0;
+        let board = Board::new(dims.clone(), mines).unwrap();
+
+        // a corner cell in 3-D has 7 neighbors (2^3 - 1), not 8
+        let corner_neighbors = board.neighbor_coords(&[0, 0, 0]).count();
+        assert_eq!(corner_neighbors, 7);
+
+        // a fully interior cell has 3^3 - 1 = 26 neighbors
+        let interior_neighbors = board.neighbor_coords(&[1, 1, 1]).count();
+        assert_eq!(interior_neighbors, 26);
+    }
+
+    #[test]
+    fn test_flag_all_flags_only_unexposed_mines() {
+        let rows = 4;
+        let columns = 4;
+        let mines = 4;
+        let mut board = Board::new_2d(rows, columns, mines).unwrap();
+
+        // expose one tile (possibly a mine) before flagging the rest
+        let _ = board.expose(vec![0, 0]);
+
+        board.flag_all();
+
+        for coord in board.coordinates() {
+            let tile = board.tile(&coord).unwrap();
+            assert_eq!(tile.flagged(), tile.mine() && !tile.exposed());
+        }
+    }
+
+    #[test]
+    fn test_exposing_a_zero_region_opens_its_full_border() {
+        let rows = 5;
+        let columns = 5;
+        let mines = 0; // every tile is zero-adjacent, so the whole board is one region
+        let mut board = Board::new_2d(rows, columns, mines).unwrap();
+
+        let border = board.region_border(&[2, 2]).unwrap();
+        assert!(border.is_some());
+        assert!(border.unwrap().is_empty());
+
+        board.expose(vec![2, 2]).unwrap();
+
+        assert_eq!(board.exposed.len(), rows * columns);
+    }
+
+    #[test]
+    fn test_expanding_board_grows_when_flood_reaches_the_edge() {
+        let mut board = Board::new_expanding(vec![3, 3], 0.0).unwrap();
+
+        board.expose(vec![1, 1]).unwrap();
+
+        // every tile was mine-free, so the initial 3x3 flooded open and
+        // pushed growth outward on every axis
+        assert!(board.rows() > 3);
+        assert!(board.columns() > 3);
+        assert_eq!(board.mine.len(), 0);
+        assert!(board.coordinates().any(|c| board.tile(&c).unwrap().exposed()));
+    }
+
+    #[test]
+    fn test_expanding_board_grows_on_the_near_edge_and_relocates_the_click() {
+        // a single row touches both the near and far edge of axis 0 at once,
+        // so growth prepends as well as appends, and the clicked coordinate
+        // must be shifted to keep pointing at the same physical tile
+        let mut board = Board::new_expanding(vec![1, 3], 0.0).unwrap();
+
+        board.expose(vec![0, 1]).unwrap();
+
+        assert_eq!(board.rows(), 1 + 2 * GROWTH_STEP);
+        assert!(board.tile(&[GROWTH_STEP, 1]).unwrap().exposed());
+    }
+
+    #[test]
+    fn test_grow_to_cover_only_shifts_axes_whose_region_touches_the_near_edge() {
+        // this checks the shift arithmetic directly, against a region shaped
+        // so one axis is prepended and the other only appended, rather than
+        // inferring it from exposure (a full-board flood would expose
+        // everything regardless of whether the shift math were wrong)
+        let mut board = Board::new_expanding(vec![3, 3], 0.0).unwrap();
+        board.seed_excluding(None);
+
+        // wall off column 0 with mines so the open region can reach axis 1's
+        // far edge but not its near edge
+        for row in 0..3 {
+            board.mine.insert(board.index_from_coord(&[row, 0]));
+        }
+        board.recompute_regions();
+
+        let region_id = board.region_of[board.index_from_coord(&[1, 1])].unwrap();
+        let shift = board.grow_to_cover(region_id);
+
+        // axis 0 (rows) spans both its edges, so it prepends; axis 1
+        // (columns) only reaches its far edge, so it only appends and
+        // contributes no shift
+        assert_eq!(shift, vec![GROWTH_STEP, 0]);
+    }
+
+    #[test]
+    fn test_expose_all_on_an_expanding_board_does_not_grow_mid_pass() {
+        // expose_all collects every coordinate up front; if it let `expose`
+        // keep growing (and so relocating) tiles mid-pass, later coordinates
+        // in that same collected batch would point at the wrong tile
+        let mut board = Board::new_expanding(vec![3, 3], 0.0).unwrap();
+        board.expose_all().unwrap();
+
+        assert_eq!(board.dims, vec![3, 3]);
+        assert_eq!(board.exposed.len(), 9);
+    }
+
+    #[test]
+    fn test_expanding_board_does_not_regrow_on_repeated_expose_of_an_open_tile() {
+        let mut board = Board::new_expanding(vec![2, 2], 0.0).unwrap();
+        board.expose(vec![0, 0]).unwrap();
+
+        let dims_after_first_expose = board.dims.clone();
+
+        // the whole board flooded open on the first click, so every later
+        // call targets an already-seen index: it must not grow again
+        for _ in 0..3 {
+            board.expose(vec![0, 0]).unwrap();
+        }
+
+        assert_eq!(board.dims, dims_after_first_expose);
+    }
+
+    #[test]
+    fn test_expanding_board_win_uses_density_check() {
+        let mut board = Board::new_expanding(vec![3, 3], 0.0).unwrap();
+
+        board.expose(vec![1, 1]).unwrap();
+
+        // no mines were ever seeded at 0 density, so every tile reachable by
+        // the flood counts as won
+        assert!(board.won());
+    }
+
+    #[test]
+    fn test_numbered_tile_has_no_region() {
+        let rows = 3;
+        let columns = 3;
+        let mines = 1;
+        let board = Board::new_2d(rows, columns, mines).unwrap();
+
+        for coord in board.coordinates() {
+            let tile = board.tile(&coord).unwrap();
+            if tile.adjacent_mines() > 0 {
+                assert!(board.region_border(&coord).unwrap().is_none());
+            }
+        }
     }
 }